@@ -0,0 +1,402 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use trading_strategies::strategies::config::RSIConfig;
+
+// Demo-side mirror of RSIConfig that can be read from a config file and written to the
+// manifest. Fields left out of a config file keep their default values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RsiConfigRecord {
+    pub rsi_period: u64,
+    pub oversold_threshold: f64,
+    pub overbought_threshold: f64,
+    // Each demo picks its own size and level mode, so a config file may not set them
+    #[serde(skip_deserializing)]
+    pub position_size: f64,
+    #[serde(skip_deserializing)]
+    pub use_dynamic_levels: bool,
+    pub volatility_window: u64,
+    pub overbought_min: f64,
+    pub overbought_max: f64,
+    pub oversold_min: f64,
+    pub oversold_max: f64,
+    pub atr_period: u64,
+    pub atr_multiplier: f64,
+}
+
+impl Default for RsiConfigRecord {
+    fn default() -> Self {
+        Self {
+            rsi_period: 14,
+            oversold_threshold: 30.0,
+            overbought_threshold: 70.0,
+            position_size: 1.0,
+            use_dynamic_levels: false,
+            volatility_window: 20,
+            overbought_min: 65.0,
+            overbought_max: 85.0,
+            oversold_min: 15.0,
+            oversold_max: 35.0,
+            atr_period: 14,
+            atr_multiplier: 2.0,
+        }
+    }
+}
+
+impl From<&RSIConfig> for RsiConfigRecord {
+    fn from(config: &RSIConfig) -> Self {
+        Self {
+            rsi_period: config.rsi_period as u64,
+            oversold_threshold: config.oversold_threshold,
+            overbought_threshold: config.overbought_threshold,
+            position_size: config.position_size,
+            use_dynamic_levels: config.use_dynamic_levels,
+            volatility_window: config.volatility_window as u64,
+            overbought_min: config.overbought_min,
+            overbought_max: config.overbought_max,
+            oversold_min: config.oversold_min,
+            oversold_max: config.oversold_max,
+            atr_period: config.atr_period as u64,
+            atr_multiplier: config.atr_multiplier,
+        }
+    }
+}
+
+impl RsiConfigRecord {
+    pub fn into_config(self) -> RSIConfig {
+        RSIConfig {
+            rsi_period: self.rsi_period as _,
+            oversold_threshold: self.oversold_threshold,
+            overbought_threshold: self.overbought_threshold,
+            position_size: self.position_size,
+            use_dynamic_levels: self.use_dynamic_levels,
+            volatility_window: self.volatility_window as _,
+            overbought_min: self.overbought_min,
+            overbought_max: self.overbought_max,
+            oversold_min: self.oversold_min,
+            oversold_max: self.oversold_max,
+            atr_period: self.atr_period as _,
+            atr_multiplier: self.atr_multiplier,
+        }
+    }
+
+    // Every violated constraint, not just the first one
+    pub fn validate(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        for (name, period) in [
+            ("rsi_period", self.rsi_period),
+            ("volatility_window", self.volatility_window),
+            ("atr_period", self.atr_period),
+        ] {
+            if period == 0 {
+                violations.push(format!("{} must be greater than 0", name));
+            }
+        }
+
+        for (name, level) in [
+            ("oversold_threshold", self.oversold_threshold),
+            ("overbought_threshold", self.overbought_threshold),
+            ("overbought_min", self.overbought_min),
+            ("overbought_max", self.overbought_max),
+            ("oversold_min", self.oversold_min),
+            ("oversold_max", self.oversold_max),
+        ] {
+            if !(0.0..=100.0).contains(&level) {
+                violations.push(format!("{} must be between 0 and 100, got {}", name, level));
+            }
+        }
+
+        let mut ordered = |lower: (&str, f64), upper: (&str, f64)| {
+            if lower.1 >= upper.1 {
+                violations.push(format!(
+                    "{} ({}) must be less than {} ({})",
+                    lower.0, lower.1, upper.0, upper.1
+                ));
+            }
+        };
+        ordered(("oversold_threshold", self.oversold_threshold), ("overbought_threshold", self.overbought_threshold));
+        ordered(("oversold_max", self.oversold_max), ("overbought_min", self.overbought_min));
+        if self.oversold_min > self.oversold_max {
+            violations.push(format!(
+                "oversold_min ({}) must not exceed oversold_max ({})",
+                self.oversold_min, self.oversold_max
+            ));
+        }
+        if self.overbought_min > self.overbought_max {
+            violations.push(format!(
+                "overbought_min ({}) must not exceed overbought_max ({})",
+                self.overbought_min, self.overbought_max
+            ));
+        }
+
+        for (name, value) in [("position_size", self.position_size), ("atr_multiplier", self.atr_multiplier)] {
+            if value <= 0.0 {
+                violations.push(format!("{} must be greater than 0, got {}", name, value));
+            }
+        }
+
+        violations
+    }
+}
+
+// `RSIConfig::builder()` for the library type, which has no builder of its own
+pub trait RSIConfigExt {
+    fn builder() -> RsiConfigBuilder;
+}
+
+impl RSIConfigExt for RSIConfig {
+    fn builder() -> RsiConfigBuilder {
+        RsiConfigBuilder::default()
+    }
+}
+
+// Starts from the default settings; only the fields that differ need setting
+#[derive(Debug, Clone, Default)]
+pub struct RsiConfigBuilder {
+    record: RsiConfigRecord,
+}
+
+impl From<RsiConfigRecord> for RsiConfigBuilder {
+    fn from(record: RsiConfigRecord) -> Self {
+        Self { record }
+    }
+}
+
+// The demos only vary a few fields, but every field gets a setter
+#[allow(dead_code)]
+impl RsiConfigBuilder {
+    pub fn rsi_period(mut self, rsi_period: u64) -> Self {
+        self.record.rsi_period = rsi_period;
+        self
+    }
+
+    pub fn oversold_threshold(mut self, oversold_threshold: f64) -> Self {
+        self.record.oversold_threshold = oversold_threshold;
+        self
+    }
+
+    pub fn overbought_threshold(mut self, overbought_threshold: f64) -> Self {
+        self.record.overbought_threshold = overbought_threshold;
+        self
+    }
+
+    pub fn position_size(mut self, position_size: f64) -> Self {
+        self.record.position_size = position_size;
+        self
+    }
+
+    pub fn use_dynamic_levels(mut self, use_dynamic_levels: bool) -> Self {
+        self.record.use_dynamic_levels = use_dynamic_levels;
+        self
+    }
+
+    pub fn volatility_window(mut self, volatility_window: u64) -> Self {
+        self.record.volatility_window = volatility_window;
+        self
+    }
+
+    pub fn overbought_min(mut self, overbought_min: f64) -> Self {
+        self.record.overbought_min = overbought_min;
+        self
+    }
+
+    pub fn overbought_max(mut self, overbought_max: f64) -> Self {
+        self.record.overbought_max = overbought_max;
+        self
+    }
+
+    pub fn oversold_min(mut self, oversold_min: f64) -> Self {
+        self.record.oversold_min = oversold_min;
+        self
+    }
+
+    pub fn oversold_max(mut self, oversold_max: f64) -> Self {
+        self.record.oversold_max = oversold_max;
+        self
+    }
+
+    pub fn atr_period(mut self, atr_period: u64) -> Self {
+        self.record.atr_period = atr_period;
+        self
+    }
+
+    pub fn atr_multiplier(mut self, atr_multiplier: f64) -> Self {
+        self.record.atr_multiplier = atr_multiplier;
+        self
+    }
+
+    pub fn build(self) -> Result<RSIConfig, ConfigError> {
+        let violations = self.record.validate();
+        if violations.is_empty() {
+            Ok(self.record.into_config())
+        } else {
+            Err(ConfigError::Invalid(violations))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ConfigFormat::Json),
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    UnsupportedFormat(String),
+    Parse(String),
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "I/O error: {}", err),
+            ConfigError::UnsupportedFormat(path) => {
+                write!(f, "unsupported config format: {} (expected .json, .toml, .yaml or .yml)", path)
+            }
+            ConfigError::Parse(message) => write!(f, "parse error: {}", message),
+            ConfigError::Invalid(violations) => {
+                write!(f, "{} invalid setting(s)", violations.len())?;
+                for violation in violations {
+                    write!(f, "\n  - {}", violation)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(err: io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
+
+fn parse_rsi_config(text: &str, format: ConfigFormat) -> Result<RsiConfigRecord, ConfigError> {
+    let parsed = match format {
+        ConfigFormat::Json => serde_json::from_str(text).map_err(|err| err.to_string()),
+        ConfigFormat::Toml => toml::from_str(text).map_err(|err| err.to_string()),
+        ConfigFormat::Yaml => serde_yaml::from_str(text).map_err(|err| err.to_string()),
+    };
+    parsed.map_err(ConfigError::Parse)
+}
+
+// Picks the parser from the file extension and rejects the config if any constraint is violated
+pub fn load_rsi_config(path: &Path) -> Result<RsiConfigRecord, ConfigError> {
+    let format = ConfigFormat::from_path(path)
+        .ok_or_else(|| ConfigError::UnsupportedFormat(path.display().to_string()))?;
+    let record = parse_rsi_config(&fs::read_to_string(path)?, format)?;
+    let violations = record.validate();
+    if violations.is_empty() {
+        Ok(record)
+    } else {
+        Err(ConfigError::Invalid(violations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(RsiConfigRecord::default().validate().is_empty());
+    }
+
+    #[test]
+    fn partial_json_keeps_defaults_and_rejects_unknown_fields() {
+        let record = parse_rsi_config(r#"{"rsi_period": 9, "atr_multiplier": 1.5}"#, ConfigFormat::Json).unwrap();
+        assert_eq!(record.rsi_period, 9);
+        assert_eq!(record.atr_multiplier, 1.5);
+        assert_eq!(record.overbought_threshold, 70.0);
+
+        assert!(parse_rsi_config(r#"{"rsi_periods": 9}"#, ConfigFormat::Json).is_err());
+    }
+
+    #[test]
+    fn toml_and_yaml_parse_to_the_same_record() {
+        let toml = parse_rsi_config("rsi_period = 9\noversold_threshold = 25.0\n", ConfigFormat::Toml).unwrap();
+        let yaml = parse_rsi_config("rsi_period: 9\noversold_threshold: 25.0\n", ConfigFormat::Yaml).unwrap();
+        assert_eq!((toml.rsi_period, toml.oversold_threshold), (9, 25.0));
+        assert_eq!((yaml.rsi_period, yaml.oversold_threshold), (9, 25.0));
+    }
+
+    #[test]
+    fn per_demo_fields_are_rejected_in_files() {
+        for text in [r#"{"position_size": 7.5}"#, r#"{"use_dynamic_levels": true}"#] {
+            assert!(matches!(parse_rsi_config(text, ConfigFormat::Json), Err(ConfigError::Parse(_))), "{}", text);
+        }
+    }
+
+    #[test]
+    fn unknown_extension_is_an_error() {
+        let result = load_rsi_config(Path::new("rsi.ini"));
+        assert!(matches!(result, Err(ConfigError::UnsupportedFormat(path)) if path == "rsi.ini"));
+    }
+
+    #[test]
+    fn every_violation_is_reported() {
+        let record = RsiConfigRecord {
+            rsi_period: 0,
+            oversold_threshold: 80.0,
+            overbought_threshold: 120.0,
+            position_size: 0.0,
+            ..RsiConfigRecord::default()
+        };
+        let violations = record.validate();
+
+        assert_eq!(violations.len(), 3, "{:?}", violations);
+        assert!(violations[0].starts_with("rsi_period"));
+        assert!(violations[1].starts_with("overbought_threshold must be between 0 and 100"));
+        assert!(violations[2].starts_with("position_size"));
+    }
+
+    #[test]
+    fn builder_starts_from_defaults() {
+        let config = RSIConfig::builder().rsi_period(9).oversold_threshold(25.0).build().unwrap();
+        let record = RsiConfigRecord::from(&config);
+        assert_eq!((record.rsi_period, record.oversold_threshold), (9, 25.0));
+        assert_eq!(record.overbought_threshold, 70.0);
+        assert_eq!(record.atr_period, 14);
+    }
+
+    #[test]
+    fn builder_validates_at_build() {
+        let result = RSIConfig::builder().oversold_threshold(75.0).position_size(0.0).build();
+        assert!(matches!(result, Err(ConfigError::Invalid(violations)) if violations.len() == 2));
+    }
+
+    #[test]
+    fn overlapping_dynamic_ranges_are_rejected() {
+        let record = RsiConfigRecord {
+            oversold_min: 40.0,
+            oversold_max: 70.0,
+            overbought_min: 60.0,
+            ..RsiConfigRecord::default()
+        };
+        let violations = record.validate();
+
+        assert_eq!(violations.len(), 1, "{:?}", violations);
+        assert!(violations[0].starts_with("oversold_max (70) must be less than overbought_min (60)"));
+    }
+}
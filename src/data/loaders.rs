@@ -0,0 +1,432 @@
+use super::MarketTick;
+use chrono::DateTime;
+use serde::Serialize;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines};
+use std::path::{Path, PathBuf};
+
+// Errors raised while reading tick files (line numbers are 1-based)
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    MissingColumn(String),
+    Parse { line: usize, message: String },
+    Unsorted { line: usize, timestamp: i64, previous: i64 },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "I/O error: {}", err),
+            LoadError::MissingColumn(name) => write!(f, "missing column '{}' in header", name),
+            LoadError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            LoadError::Unsorted { line, timestamp, previous } => write!(
+                f,
+                "line {}: timestamp {} is earlier than previous tick {}",
+                line, timestamp, previous
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+    fn from(err: io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+// Ticks must never go backwards in time (equal timestamps are fine). The first
+// out-of-order row halts the iterator: without the rest of the file there is no way
+// to tell whether that row or an earlier outlier is the bad one
+#[derive(Debug, Default)]
+struct OrderCheck {
+    previous: Option<i64>,
+    halted: bool,
+}
+
+impl OrderCheck {
+    fn check(&mut self, line: usize, tick: MarketTick) -> Result<MarketTick, LoadError> {
+        if let Some(previous) = self.previous {
+            if tick.timestamp < previous {
+                self.halted = true;
+                return Err(LoadError::Unsorted { line, timestamp: tick.timestamp, previous });
+            }
+        }
+        self.previous = Some(tick.timestamp);
+        Ok(tick)
+    }
+}
+
+// Unit of the integer epoch timestamps in a file. Ticks always come out in epoch
+// milliseconds, so second-epoch files and RFC 3339 strings line up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampUnit {
+    #[default]
+    Milliseconds,
+    Seconds,
+}
+
+impl TimestampUnit {
+    fn to_millis(self, value: i64) -> Option<i64> {
+        match self {
+            TimestampUnit::Milliseconds => Some(value),
+            TimestampUnit::Seconds => value.checked_mul(1000),
+        }
+    }
+}
+
+// Integer epochs are scaled from `unit`, RFC 3339 strings are converted directly
+fn parse_timestamp(value: &str, unit: TimestampUnit) -> Option<i64> {
+    match value.parse::<i64>() {
+        Ok(epoch) => unit.to_millis(epoch),
+        Err(_) => DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|dt| dt.timestamp_millis()),
+    }
+}
+
+// Next raw line, counted even when it fails; invalid UTF-8 is a malformed row, not an I/O failure
+fn next_line(lines: &mut Lines<BufReader<File>>, line: &mut usize) -> Option<Result<String, LoadError>> {
+    let next = lines.next()?;
+    *line += 1;
+    Some(next.map_err(|err| match err.kind() {
+        io::ErrorKind::InvalidData => LoadError::Parse { line: *line, message: err.to_string() },
+        _ => LoadError::Io(err),
+    }))
+}
+
+// JSON Lines loader for files like stochastic_hooks_demo.jsonl
+pub struct JsonlTickLoader {
+    path: PathBuf,
+    timestamp_unit: TimestampUnit,
+}
+
+impl JsonlTickLoader {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            timestamp_unit: TimestampUnit::default(),
+        }
+    }
+
+    pub fn with_timestamp_unit(mut self, unit: TimestampUnit) -> Self {
+        self.timestamp_unit = unit;
+        self
+    }
+
+    pub fn ticks(&self) -> Result<JsonlTicks, LoadError> {
+        let file = File::open(&self.path)?;
+        Ok(JsonlTicks {
+            lines: BufReader::new(file).lines(),
+            line: 0,
+            timestamp_unit: self.timestamp_unit,
+            order: OrderCheck::default(),
+        })
+    }
+}
+
+pub struct JsonlTicks {
+    lines: Lines<BufReader<File>>,
+    line: usize,
+    timestamp_unit: TimestampUnit,
+    order: OrderCheck,
+}
+
+impl JsonlTicks {
+    fn parse_line(&self, text: &str) -> Result<MarketTick, LoadError> {
+        let parse_error = |message: String| LoadError::Parse { line: self.line, message };
+        let mut tick = serde_json::from_str::<MarketTick>(text).map_err(|err| parse_error(err.to_string()))?;
+        tick.timestamp = self
+            .timestamp_unit
+            .to_millis(tick.timestamp)
+            .ok_or_else(|| parse_error(format!("timestamp {} out of range", tick.timestamp)))?;
+        Ok(tick)
+    }
+}
+
+impl Iterator for JsonlTicks {
+    type Item = Result<MarketTick, LoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order.halted {
+            return None;
+        }
+        loop {
+            let text = match next_line(&mut self.lines, &mut self.line)? {
+                Ok(text) => text,
+                Err(err) => return Some(Err(err)),
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let tick = self.parse_line(&text);
+            return Some(tick.and_then(|tick| self.order.check(self.line, tick)));
+        }
+    }
+}
+
+// Header names the CSV loader reads each tick field from
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnMapping {
+    pub timestamp: String,
+    pub price: String,
+    pub volume: String,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            timestamp: "timestamp".to_string(),
+            price: "price".to_string(),
+            volume: "volume".to_string(),
+        }
+    }
+}
+
+// Comma-separated loader; the first line must be a header
+pub struct CsvTickLoader {
+    path: PathBuf,
+    columns: ColumnMapping,
+    timestamp_unit: TimestampUnit,
+}
+
+impl CsvTickLoader {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            columns: ColumnMapping::default(),
+            timestamp_unit: TimestampUnit::default(),
+        }
+    }
+
+    pub fn with_columns(mut self, columns: ColumnMapping) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    pub fn with_timestamp_unit(mut self, unit: TimestampUnit) -> Self {
+        self.timestamp_unit = unit;
+        self
+    }
+
+    pub fn ticks(&self) -> Result<CsvTicks, LoadError> {
+        let file = File::open(&self.path)?;
+        let mut lines = BufReader::new(file).lines();
+        let header = lines.next().transpose()?.unwrap_or_default();
+        // Spreadsheet exports often start with a UTF-8 byte order mark
+        let header = header.strip_prefix('\u{feff}').unwrap_or(&header);
+        let names: Vec<&str> = header.split(',').map(str::trim).collect();
+
+        let index_of = |column: &str| {
+            names
+                .iter()
+                .position(|name| *name == column)
+                .ok_or_else(|| LoadError::MissingColumn(column.to_string()))
+        };
+
+        Ok(CsvTicks {
+            lines,
+            line: 1,
+            timestamp_index: index_of(&self.columns.timestamp)?,
+            price_index: index_of(&self.columns.price)?,
+            volume_index: index_of(&self.columns.volume)?,
+            timestamp_unit: self.timestamp_unit,
+            order: OrderCheck::default(),
+        })
+    }
+}
+
+pub struct CsvTicks {
+    lines: Lines<BufReader<File>>,
+    line: usize,
+    timestamp_index: usize,
+    price_index: usize,
+    volume_index: usize,
+    timestamp_unit: TimestampUnit,
+    order: OrderCheck,
+}
+
+impl CsvTicks {
+    fn parse_row(&self, text: &str) -> Result<MarketTick, LoadError> {
+        let fields: Vec<&str> = text.split(',').map(str::trim).collect();
+        let field = |index: usize, name: &str| {
+            fields.get(index).copied().ok_or_else(|| LoadError::Parse {
+                line: self.line,
+                message: format!("missing {} field", name),
+            })
+        };
+        let invalid = |name: &str, value: &str| LoadError::Parse {
+            line: self.line,
+            message: format!("invalid {} '{}'", name, value),
+        };
+
+        let timestamp = field(self.timestamp_index, "timestamp")?;
+        let price = field(self.price_index, "price")?;
+        let volume = field(self.volume_index, "volume")?;
+
+        Ok(MarketTick {
+            timestamp: parse_timestamp(timestamp, self.timestamp_unit).ok_or_else(|| invalid("timestamp", timestamp))?,
+            price: price.parse().map_err(|_| invalid("price", price))?,
+            volume: volume.parse().map_err(|_| invalid("volume", volume))?,
+        })
+    }
+}
+
+impl Iterator for CsvTicks {
+    type Item = Result<MarketTick, LoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.order.halted {
+            return None;
+        }
+        loop {
+            let text = match next_line(&mut self.lines, &mut self.line)? {
+                Ok(text) => text,
+                Err(err) => return Some(Err(err)),
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let tick = self.parse_row(&text);
+            return Some(tick.and_then(|tick| self.order.check(self.line, tick)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_temp(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("loaders_{}_{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn timestamps(ticks: &[Result<MarketTick, LoadError>]) -> Vec<Option<i64>> {
+        ticks.iter().map(|tick| tick.as_ref().ok().map(|t| t.timestamp)).collect()
+    }
+
+    #[test]
+    fn parse_timestamp_accepts_epochs_and_rfc3339() {
+        let ms = TimestampUnit::Milliseconds;
+        assert_eq!(parse_timestamp("1752540000000", ms), Some(1752540000000));
+        assert_eq!(parse_timestamp("-5", ms), Some(-5));
+        assert_eq!(parse_timestamp("2025-07-15T00:00:00Z", ms), Some(1752537600000));
+        assert_eq!(parse_timestamp("2025-07-15T02:00:00+02:00", ms), Some(1752537600000));
+        assert_eq!(parse_timestamp("2025-07-15", ms), None);
+        assert_eq!(parse_timestamp("", ms), None);
+    }
+
+    #[test]
+    fn second_epochs_are_scaled_to_match_rfc3339() {
+        let s = TimestampUnit::Seconds;
+        assert_eq!(parse_timestamp("1752537600", s), parse_timestamp("2025-07-15T00:00:00Z", s));
+        assert_eq!(parse_timestamp(&i64::MAX.to_string(), s), None);
+    }
+
+    #[test]
+    fn csv_columns_are_looked_up_by_mapped_name() {
+        let path = write_temp("mapping.csv", "qty, ts ,px\n2,1000,3000.5\n");
+        let columns = ColumnMapping {
+            timestamp: "ts".to_string(),
+            price: "px".to_string(),
+            volume: "qty".to_string(),
+        };
+        let ticks: Vec<_> = CsvTickLoader::new(&path).with_columns(columns).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        let tick = ticks[0].as_ref().unwrap();
+        assert_eq!((tick.timestamp, tick.price, tick.volume), (1000, 3000.5, 2.0));
+    }
+
+    #[test]
+    fn csv_missing_column_fails_before_reading_rows() {
+        let path = write_temp("missing.csv", "timestamp,price\n1000,3000\n");
+        let result = CsvTickLoader::new(&path).ticks();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(LoadError::MissingColumn(name)) if name == "volume"));
+    }
+
+    #[test]
+    fn csv_malformed_row_is_reported_and_loading_continues() {
+        let path = write_temp("malformed.csv", "timestamp,price,volume\n1000,3000,1\nbad,1,1\n2000,3001,1\n");
+        let ticks: Vec<_> = CsvTickLoader::new(&path).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(ticks[1], Err(LoadError::Parse { line: 3, .. })));
+        assert_eq!(timestamps(&ticks), vec![Some(1000), None, Some(2000)]);
+    }
+
+    #[test]
+    fn csv_header_byte_order_mark_is_ignored() {
+        let path = write_temp("bom.csv", "\u{feff}timestamp,price,volume\n1000,3000,1\n");
+        let ticks: Vec<_> = CsvTickLoader::new(&path).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(timestamps(&ticks), vec![Some(1000)]);
+    }
+
+    #[test]
+    fn invalid_utf8_line_is_a_parse_error_and_loading_continues() {
+        let path = std::env::temp_dir().join(format!("loaders_{}_utf8.jsonl", std::process::id()));
+        let mut contents = b"{\"timestamp\":1,\"price\":1.0,\"volume\":0}\n".to_vec();
+        contents.extend_from_slice(b"\xff\xfe\n");
+        contents.extend_from_slice(b"{\"timestamp\":2,\"price\":1.0,\"volume\":0}\n");
+        fs::write(&path, contents).unwrap();
+        let ticks: Vec<_> = JsonlTickLoader::new(&path).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(ticks[1], Err(LoadError::Parse { line: 2, .. })));
+        assert_eq!(timestamps(&ticks), vec![Some(1), None, Some(2)]);
+    }
+
+    #[test]
+    fn jsonl_second_epochs_come_out_in_milliseconds() {
+        let path = write_temp("seconds.jsonl", "{\"timestamp\":1752537600,\"price\":1.0,\"volume\":0}\n");
+        let ticks: Vec<_> = JsonlTickLoader::new(&path)
+            .with_timestamp_unit(TimestampUnit::Seconds)
+            .ticks()
+            .unwrap()
+            .collect();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(timestamps(&ticks), vec![Some(1752537600000)]);
+    }
+
+    #[test]
+    fn out_of_order_row_halts_the_iterator() {
+        let path = write_temp(
+            "unsorted.csv",
+            "timestamp,price,volume\n1000,1,1\n999999999999,1,1\n3000,1,1\n4000,1,1\n5000,1,1\n",
+        );
+        let ticks: Vec<_> = CsvTickLoader::new(&path).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(ticks.len(), 3);
+        assert!(matches!(
+            ticks[2],
+            Err(LoadError::Unsorted { line: 4, timestamp: 3000, previous: 999999999999 })
+        ));
+    }
+
+    #[test]
+    fn jsonl_equal_timestamps_are_sorted() {
+        let path = write_temp(
+            "equal.jsonl",
+            "{\"timestamp\":1,\"price\":1.0,\"volume\":0}\n\n{\"timestamp\":1,\"price\":2.0,\"volume\":0}\n",
+        );
+        let ticks: Vec<_> = JsonlTickLoader::new(&path).ticks().unwrap().collect();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(timestamps(&ticks), vec![Some(1), Some(1)]);
+    }
+}
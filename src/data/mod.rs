@@ -0,0 +1,28 @@
+use trading_strategies::core::tick::TickData;
+use serde::{Deserialize, Serialize};
+
+pub mod loaders;
+pub mod synthetic;
+
+// Simple tick data structure
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarketTick {
+    pub timestamp: i64,
+    pub price: f64,
+    pub volume: f64,
+}
+
+impl TickData for MarketTick {
+    fn timestamp(&self) -> i64 { self.timestamp }
+    fn price(&self) -> f64 { self.price }
+    fn volume(&self) -> f64 { self.volume }
+    fn symbol(&self) -> &str { "BTCUSDT" }
+}
+
+// Simple custom data for demo
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeMetadata {
+    pub user_id: String,
+    pub session_id: String,
+    pub risk_level: String,
+}
@@ -0,0 +1,195 @@
+use super::MarketTick;
+use std::f64::consts::PI;
+
+// Small deterministic RNG (SplitMix64) so the same seed always yields the same path
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Standard normal via Box-Muller
+    pub fn next_normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+// Price dynamics; all rates are per tick step
+#[derive(Debug, Clone, Copy)]
+pub enum PriceModel {
+    // Geometric Brownian motion
+    Gbm { drift: f64, volatility: f64 },
+    // Log-price pulled back toward `mean` at speed `reversion` (0..1)
+    OrnsteinUhlenbeck { mean: f64, reversion: f64, volatility: f64 },
+    // GBM that flips between a calm and a stressed volatility regime
+    RegimeSwitching {
+        drift: f64,
+        calm_volatility: f64,
+        stressed_volatility: f64,
+        switch_probability: f64,
+    },
+}
+
+// Volume oscillating around `base` with a sine cycle of `period` ticks plus noise
+#[derive(Debug, Clone, Copy)]
+pub struct VolumeProfile {
+    pub base: f64,
+    pub amplitude: f64,
+    pub period: usize,
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self { base: 1.0, amplitude: 0.5, period: 60 }
+    }
+}
+
+pub struct SyntheticTickGenerator {
+    model: PriceModel,
+    volume: VolumeProfile,
+    start_price: f64,
+    start_timestamp: i64,
+    interval: i64,
+    rng: SeededRng,
+}
+
+impl SyntheticTickGenerator {
+    pub fn new(model: PriceModel, seed: u64) -> Self {
+        Self {
+            model,
+            volume: VolumeProfile::default(),
+            start_price: 45000.0,
+            start_timestamp: 1752540000000,
+            interval: 10_000,
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    pub fn with_start_price(mut self, price: f64) -> Self {
+        self.start_price = price;
+        self
+    }
+
+    pub fn with_volume(mut self, volume: VolumeProfile) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn generate(mut self, count: usize) -> Vec<MarketTick> {
+        let mut price = self.start_price;
+        let mut stressed = false;
+        let mut ticks = Vec::with_capacity(count);
+
+        for step in 0..count {
+            ticks.push(MarketTick {
+                timestamp: self.start_timestamp + step as i64 * self.interval,
+                price,
+                volume: self.next_volume(step),
+            });
+
+            let shock = self.rng.next_normal();
+            price = match self.model {
+                PriceModel::Gbm { drift, volatility } => gbm_step(price, drift, volatility, shock),
+                PriceModel::OrnsteinUhlenbeck { mean, reversion, volatility } => {
+                    let log_price = price.ln();
+                    (log_price + reversion * (mean.ln() - log_price) + volatility * shock).exp()
+                }
+                PriceModel::RegimeSwitching { drift, calm_volatility, stressed_volatility, switch_probability } => {
+                    if self.rng.next_f64() < switch_probability {
+                        stressed = !stressed;
+                    }
+                    let volatility = if stressed { stressed_volatility } else { calm_volatility };
+                    gbm_step(price, drift, volatility, shock)
+                }
+            };
+        }
+
+        ticks
+    }
+
+    fn next_volume(&mut self, step: usize) -> f64 {
+        let phase = 2.0 * PI * step as f64 / self.volume.period.max(1) as f64;
+        let seasonal = 1.0 + self.volume.amplitude * phase.sin();
+        let noise = 0.5 + self.rng.next_f64();
+        (self.volume.base * seasonal * noise).max(0.0)
+    }
+}
+
+fn gbm_step(price: f64, drift: f64, volatility: f64, shock: f64) -> f64 {
+    price * (drift - 0.5 * volatility * volatility + volatility * shock).exp()
+}
+
+// Hand-picked ticks covering every pre-trade hook outcome
+pub fn sample_ticks() -> Vec<MarketTick> {
+    vec![
+        MarketTick { timestamp: 1000, price: 45000.0, volume: 1.0 },
+        MarketTick { timestamp: 2000, price: 46000.0, volume: 1.5 },
+        MarketTick { timestamp: 3000, price: 47000.0, volume: 2.0 },
+        MarketTick { timestamp: 4000, price: 51000.0, volume: 1.0 }, // High price - should be rejected
+        MarketTick { timestamp: 5000, price: 48000.0, volume: 3.0 },
+        MarketTick { timestamp: 6000, price: 49000.0, volume: 2.5 },
+        MarketTick { timestamp: 7000, price: 47500.0, volume: 1.8 },
+        MarketTick { timestamp: 8000, price: 46500.0, volume: 2.2 },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(ticks: &[MarketTick]) -> Vec<(i64, f64, f64)> {
+        ticks.iter().map(|tick| (tick.timestamp, tick.price, tick.volume)).collect()
+    }
+
+    fn gbm() -> PriceModel {
+        PriceModel::Gbm { drift: 0.0, volatility: 0.01 }
+    }
+
+    #[test]
+    fn same_seed_gives_identical_ticks() {
+        let first = SyntheticTickGenerator::new(gbm(), 7).generate(200);
+        let second = SyntheticTickGenerator::new(gbm(), 7).generate(200);
+        assert_eq!(fields(&first), fields(&second));
+    }
+
+    #[test]
+    fn different_seeds_give_different_paths() {
+        let first = SyntheticTickGenerator::new(gbm(), 7).generate(200);
+        let second = SyntheticTickGenerator::new(gbm(), 8).generate(200);
+        assert_ne!(fields(&first), fields(&second));
+    }
+
+    #[test]
+    fn ticks_are_evenly_spaced_with_non_negative_volume() {
+        let ticks = SyntheticTickGenerator::new(gbm(), 1).generate(100);
+        assert!(ticks.windows(2).all(|pair| pair[1].timestamp - pair[0].timestamp == 10_000));
+        assert!(ticks.iter().all(|tick| tick.volume >= 0.0));
+    }
+
+    #[test]
+    fn ornstein_uhlenbeck_stays_close_to_the_mean() {
+        let model = PriceModel::OrnsteinUhlenbeck { mean: 45000.0, reversion: 0.05, volatility: 0.002 };
+        let ticks = SyntheticTickGenerator::new(model, 42).generate(10_000);
+        // Stationary std dev of the log price is about 0.002 / sqrt(0.1) ~ 0.6%
+        assert!(ticks.iter().all(|tick| (tick.price / 45000.0 - 1.0).abs() < 0.05));
+    }
+}
@@ -1,54 +1,46 @@
 use trading_strategies::Strategy;
 use trading_strategies::core::{ProposedTrade, TradeDecision, TradeEvent, TradeObserver};
 use trading_strategies::core::types::TradeContext;
-use trading_strategies::core::tick::TickData;
 use trading_strategies::core::tick_strategy::TickStrategyWrapper;
 use trading_strategies::strategies::config::RSIConfig;
-use trading_strategies::strategies::rsi::{RSIStrategy, RsiTradeContext};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use serde::{Deserialize, Serialize};
-
-// Simple tick data structure
-#[derive(Debug, Clone, Deserialize, Serialize)]
-struct MarketTick {
-    timestamp: i64,
-    price: f64,
-    volume: f64,
-}
-
-impl TickData for MarketTick {
-    fn timestamp(&self) -> i64 { self.timestamp }
-    fn price(&self) -> f64 { self.price }
-    fn volume(&self) -> f64 { self.volume }
-    fn symbol(&self) -> &str { "BTCUSDT" }
-}
-
-// Simple custom data for demo
-#[derive(Debug, Clone)]
-struct TradeMetadata {
-    user_id: String,
-    session_id: String,
-    risk_level: String,
-}
+use trading_strategies::strategies::rsi::RSIStrategy;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod config;
+mod data;
+mod manifest;
+mod observers;
+mod replay;
+mod reporting;
+
+use config::{RSIConfigExt, RsiConfigBuilder};
+use data::{MarketTick, TradeMetadata};
+use data::loaders::{ColumnMapping, CsvTickLoader, JsonlTickLoader, LoadError, TimestampUnit};
+use data::synthetic::{self, PriceModel, SyntheticTickGenerator, VolumeProfile};
+use manifest::{DataSource, FileFormat, RunManifest};
+use observers::{DecisionStats, RiskLimits, RiskManager};
+use replay::{Clock, ReplayControl, ReplayEngine, ReplaySpeed, SimulatedClock, SystemClock, MIN_MULTIPLIER};
+use reporting::{JournalEntry, TradeJournal};
 
 // Demo 1: Pre-trade hooks (modify, reject, approve)
 struct PreTradeHookDemo {
     max_position_size: f64,
-    rejected_count: usize,
-    modified_count: usize,
-    approved_count: usize,
+    stats: Arc<Mutex<DecisionStats>>,
 }
 
 impl PreTradeHookDemo {
     fn new(max_position_size: f64) -> Self {
         Self {
             max_position_size,
-            rejected_count: 0,
-            modified_count: 0,
-            approved_count: 0,
+            stats: Arc::new(Mutex::new(DecisionStats::default())),
         }
     }
+
+    fn stats_handle(&self) -> Arc<Mutex<DecisionStats>> {
+        Arc::clone(&self.stats)
+    }
 }
 
 impl TradeObserver for PreTradeHookDemo {
@@ -56,9 +48,11 @@ impl TradeObserver for PreTradeHookDemo {
         println!("Pre-trade Hook: Evaluating trade at ${:.2}, size: {:.2}", 
                  proposed_trade.price, proposed_trade.quantity);
 
+        let mut stats = self.stats.lock().unwrap();
+
         // Rule 1: Reject trades above $50,000
         if proposed_trade.price > 50000.0 {
-            self.rejected_count += 1;
+            stats.rejected_count += 1;
             println!("  → REJECTED: Price too high (${:.2} > $50,000)", proposed_trade.price);
             return TradeDecision::Reject("Price too high".to_string());
         }
@@ -68,14 +62,14 @@ impl TradeObserver for PreTradeHookDemo {
             let old_size = proposed_trade.quantity;
             let mut modified_trade = proposed_trade.clone();
             modified_trade.quantity = self.max_position_size;
-            self.modified_count += 1;
+            stats.modified_count += 1;
             println!("  → MODIFIED: Position size reduced from {:.2} to {:.2}", 
                      old_size, modified_trade.quantity);
             return TradeDecision::Modify(modified_trade);
         }
 
         // Rule 3: Approve normal trades
-        self.approved_count += 1;
+        stats.approved_count += 1;
         println!("  → APPROVED: Trade looks good");
         TradeDecision::Approve
     }
@@ -103,118 +97,211 @@ impl TradeObserver for ContextDemo {
 
     fn post_trade(&mut self, event: TradeEvent, context: TradeContext) {
         self.trade_count += 1;
-        
-        let (side, trade) = match event {
-            TradeEvent::Buy(trade) => ("Long", trade),
-            TradeEvent::Sell(trade) => ("Short", trade),
-        };
-        
-        println!("Trade #{}: {} at ${:.2}", 
-                 self.trade_count, side, trade.exit_price);
+
+        // Same extraction the trade journal persists
+        let entry = JournalEntry::from_event(self.trade_count, &event, &context);
+        println!("Trade #{}: {} at ${:.2}",
+                 entry.trade_number, entry.side, entry.price);
 
         // Show strategy context if available
-        if let Some(rsi_context) = context.strategy_context
-            .and_then(|ctx| ctx.downcast_ref::<RsiTradeContext>()) {
+        if let Some(rsi) = &entry.rsi {
             println!("  Strategy Context:");
-            println!("    RSI Value: {:.2}", rsi_context.rsi_value);
-            println!("    Overbought Level: {:.2}", rsi_context.dynamic_overbought);
-            println!("    Oversold Level: {:.2}", rsi_context.dynamic_oversold);
+            println!("    RSI Value: {:.2}", rsi.rsi_value);
+            println!("    Overbought Level: {:.2}", rsi.overbought_level);
+            println!("    Oversold Level: {:.2}", rsi.oversold_level);
         }
 
         // Show custom data if available
-        if let Some(custom_data) = context.custom_data {
-            if let Some(metadata) = custom_data.downcast_ref::<TradeMetadata>() {
-                println!("  Custom Data:");
-                println!("    User ID: {}", metadata.user_id);
-                println!("    Session ID: {}", metadata.session_id);
-                println!("    Risk Level: {}", metadata.risk_level);
-            }
+        if let Some(metadata) = &entry.metadata {
+            println!("  Custom Data:");
+            println!("    User ID: {}", metadata.user_id);
+            println!("    Session ID: {}", metadata.session_id);
+            println!("    Risk Level: {}", metadata.risk_level);
         }
         println!();
     }
 }
 
-fn load_ticks() -> Vec<MarketTick> {
-    let file_path = "stochastic_hooks_demo.jsonl";
-    let file = match File::open(file_path) {
-        Ok(f) => f,
-        Err(_) => {
-            println!("Warning: Could not load {}, using sample data", file_path);
-            return create_sample_ticks();
+// Usage: cargo run -- [ticks.jsonl | ticks.csv [timestamp_col price_col volume_col]]
+fn load_ticks() -> (Vec<MarketTick>, DataSource) {
+    if let Ok(model_name) = std::env::var("SYNTHETIC_TICKS") {
+        match synthetic_ticks(&model_name) {
+            Some(loaded) => return loaded,
+            None => println!("Warning: Unknown SYNTHETIC_TICKS model '{}', loading file data", model_name),
+        }
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let file_path = args.first().map(String::as_str).unwrap_or("stochastic_hooks_demo.jsonl");
+
+    // TICK_TIMESTAMP_UNIT=ms (default) or =s says what integer timestamps in the file are;
+    // loaded ticks are always in milliseconds
+    let timestamp_unit = match std::env::var("TICK_TIMESTAMP_UNIT").as_deref() {
+        Ok("s") => TimestampUnit::Seconds,
+        Ok("ms") | Err(_) => TimestampUnit::Milliseconds,
+        Ok(value) => {
+            println!("Warning: Invalid TICK_TIMESTAMP_UNIT '{}', using milliseconds", value);
+            TimestampUnit::Milliseconds
         }
     };
 
-    let reader = BufReader::new(file);
-    let mut ticks = Vec::new();
-    
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            if let Ok(tick) = serde_json::from_str::<MarketTick>(&line) {
-                ticks.push(tick);
-            }
+    let (loaded, format) = if file_path.ends_with(".csv") {
+        let columns = match args.as_slice() {
+            [_, timestamp, price, volume] => ColumnMapping {
+                timestamp: timestamp.clone(),
+                price: price.clone(),
+                volume: volume.clone(),
+            },
+            _ => ColumnMapping::default(),
+        };
+        let loaded = CsvTickLoader::new(file_path)
+            .with_columns(columns.clone())
+            .with_timestamp_unit(timestamp_unit)
+            .ticks()
+            .and_then(collect_ticks);
+        (loaded, FileFormat::Csv { columns })
+    } else {
+        let loaded = JsonlTickLoader::new(file_path)
+            .with_timestamp_unit(timestamp_unit)
+            .ticks()
+            .and_then(collect_ticks);
+        (loaded, FileFormat::Jsonl)
+    };
+
+    match loaded {
+        Ok(ticks) if !ticks.is_empty() => (ticks, DataSource::file(file_path, format, timestamp_unit)),
+        Ok(_) => (synthetic::sample_ticks(), DataSource::Sample),
+        Err(err) => {
+            println!("Warning: Could not load {} ({}), using sample data", file_path, err);
+            (synthetic::sample_ticks(), DataSource::Sample)
         }
     }
-    
-    if ticks.is_empty() {
-        create_sample_ticks()
-    } else {
-        ticks
+}
+
+// Skip malformed lines with a warning; out-of-order data or I/O errors fail the whole load
+fn collect_ticks(ticks: impl Iterator<Item = Result<MarketTick, LoadError>>) -> Result<Vec<MarketTick>, LoadError> {
+    let mut collected = Vec::new();
+    for tick in ticks {
+        match tick {
+            Ok(tick) => collected.push(tick),
+            Err(err @ LoadError::Parse { .. }) => println!("Warning: Skipping tick, {}", err),
+            Err(err) => return Err(err),
+        }
     }
+    Ok(collected)
 }
 
-fn create_sample_ticks() -> Vec<MarketTick> {
-    vec![
-        MarketTick { timestamp: 1000, price: 45000.0, volume: 1.0 },
-        MarketTick { timestamp: 2000, price: 46000.0, volume: 1.5 },
-        MarketTick { timestamp: 3000, price: 47000.0, volume: 2.0 },
-        MarketTick { timestamp: 4000, price: 51000.0, volume: 1.0 }, // High price - should be rejected
-        MarketTick { timestamp: 5000, price: 48000.0, volume: 3.0 },
-        MarketTick { timestamp: 6000, price: 49000.0, volume: 2.5 },
-        MarketTick { timestamp: 7000, price: 47500.0, volume: 1.8 },
-        MarketTick { timestamp: 8000, price: 46500.0, volume: 2.2 },
-    ]
+// SYNTHETIC_TICKS=gbm|ou|regime replaces file data with a generated path, seeded by SYNTHETIC_SEED
+fn synthetic_ticks(model_name: &str) -> Option<(Vec<MarketTick>, DataSource)> {
+    let model = match model_name {
+        "gbm" => PriceModel::Gbm { drift: 0.0, volatility: 0.002 },
+        "ou" => PriceModel::OrnsteinUhlenbeck { mean: 47000.0, reversion: 0.05, volatility: 0.002 },
+        "regime" => PriceModel::RegimeSwitching {
+            drift: 0.0,
+            calm_volatility: 0.001,
+            stressed_volatility: 0.006,
+            switch_probability: 0.02,
+        },
+        _ => return None,
+    };
+    let seed = match std::env::var("SYNTHETIC_SEED") {
+        Ok(value) => value.parse().unwrap_or_else(|_| {
+            println!("Warning: Invalid SYNTHETIC_SEED '{}', using 42", value);
+            42
+        }),
+        Err(_) => 42,
+    };
+
+    println!("Generating synthetic '{}' ticks (seed {})", model_name, seed);
+    let ticks = SyntheticTickGenerator::new(model, seed)
+        .with_start_price(47000.0)
+        // One volume cycle per hour of 10-second ticks
+        .with_volume(VolumeProfile { base: 2.0, amplitude: 0.8, period: 360 })
+        .generate(500);
+    Some((ticks, DataSource::Synthetic { model: model_name.to_string(), seed }))
+}
+
+// Set RSI_CONFIG=rsi.toml (or .json, .yaml) to replace the built-in RSI settings
+fn load_rsi_settings() -> RsiConfigBuilder {
+    let Ok(config_path) = std::env::var("RSI_CONFIG") else {
+        return RSIConfig::builder();
+    };
+    match config::load_rsi_config(Path::new(&config_path)) {
+        Ok(record) => {
+            println!("Using RSI settings from {}\n", config_path);
+            RsiConfigBuilder::from(record)
+        }
+        Err(err) => {
+            println!("Warning: Could not load RSI config {}: {}", config_path, err);
+            println!("Using built-in RSI settings\n");
+            RSIConfig::builder()
+        }
+    }
 }
 
 fn main() {
     println!("=== Trading Hooks Demo ===\n");
 
-    let ticks = load_ticks();
+    let (ticks, data_source) = load_ticks();
     println!("Loaded {} ticks for demo\n", ticks.len());
+    let rsi_settings = load_rsi_settings();
+    let mut manifest = RunManifest::new(data_source, ticks.len());
 
     // Demo 1: Pre-trade hooks (modify, reject, approve)
     println!("--- Demo 1: Pre-trade Hooks ---");
-    demo_pre_trade_hooks(&ticks);
+    demo_pre_trade_hooks(&ticks, &rsi_settings, &mut manifest);
 
     println!("\n{}\n", "=".repeat(50));
 
     // Demo 2: Strategy context and custom data
     println!("--- Demo 2: Strategy Context & Custom Data ---");
-    demo_strategy_context(&ticks);
+    demo_strategy_context(&ticks, &rsi_settings, &mut manifest);
+
+    println!("\n{}\n", "=".repeat(50));
+
+    // Demo 3: Replay with a controllable clock
+    println!("--- Demo 3: Replay Engine ---");
+    demo_replay(&ticks, &rsi_settings, &mut manifest);
+
+    println!("\n{}\n", "=".repeat(50));
+
+    // Demo 4: Ready-made risk manager observer
+    println!("--- Demo 4: Risk Manager ---");
+    demo_risk_manager(&ticks, &rsi_settings, &mut manifest);
+
+    println!("\nRun ID: {}", manifest.run_id());
+    // Set RUN_MANIFEST=run.json to save data hashes, seeds and configs for reproducing this run
+    if let Ok(manifest_path) = std::env::var("RUN_MANIFEST") {
+        match manifest.write_to(Path::new(&manifest_path)) {
+            Ok(()) => println!("Run manifest written to {}", manifest_path),
+            Err(err) => println!("Warning: Could not write run manifest {}: {}", manifest_path, err),
+        }
+    }
+}
+
+// Builds a demo's strategy and records it in the manifest, so the recorded values are the ones used
+fn build_wrapper(name: &str, config: RSIConfig, manifest: &mut RunManifest) -> TickStrategyWrapper<RSIStrategy> {
+    let initial_capital = 100000.0;
+    let candle_interval = 5;
+    manifest.add_rsi_strategy(name, &config, initial_capital, candle_interval as u64);
+    TickStrategyWrapper::new(RSIStrategy::new(config, initial_capital), candle_interval)
 }
 
-fn demo_pre_trade_hooks(ticks: &[MarketTick]) {
+fn demo_pre_trade_hooks(ticks: &[MarketTick], rsi_settings: &RsiConfigBuilder, manifest: &mut RunManifest) {
     println!("Testing pre-trade hooks: modify, reject, approve trades\n");
 
-    let config = RSIConfig {
-        rsi_period: 14,
-        oversold_threshold: 30.0,
-        overbought_threshold: 70.0,
-        position_size: 3.0, // Large size to trigger modifications
-        use_dynamic_levels: false,
-        volatility_window: 20,
-        overbought_min: 65.0,
-        overbought_max: 85.0,
-        oversold_min: 15.0,
-        oversold_max: 35.0,
-        atr_period: 14,
-        atr_multiplier: 2.0,
-    };
+    let config = rsi_settings
+        .clone()
+        .position_size(3.0) // Large size to trigger modifications
+        .use_dynamic_levels(false)
+        .build()
+        .expect("demo sizes are positive and loaded settings are already validated");
 
-    let rsi_strategy = RSIStrategy::new(config, 100000.0);
-    let mut rsi_wrapper = TickStrategyWrapper::new(rsi_strategy, 5);
+    let mut rsi_wrapper = build_wrapper("pre_trade_hooks", config, manifest);
 
     // Add pre-trade hook observer
     let hook_observer = PreTradeHookDemo::new(2.0); // Max position size: 2.0
+    let hook_stats = hook_observer.stats_handle();
     rsi_wrapper.strategy_mut().add_observer(Box::new(hook_observer));
 
     println!("Processing ticks...\n");
@@ -226,36 +313,31 @@ fn demo_pre_trade_hooks(ticks: &[MarketTick]) {
         rsi_wrapper.force_close_candle(last_tick.timestamp + 1000);
     }
 
-    println!("\nPre-trade Hook Results:");
-    println!("  Note: Observer results would be tracked separately in a real implementation");
-    println!("  Check console output above for individual trade decisions");
+    println!();
+    hook_stats.lock().unwrap().print("Pre-trade Hook Results");
 }
 
-fn demo_strategy_context(ticks: &[MarketTick]) {
+fn demo_strategy_context(ticks: &[MarketTick], rsi_settings: &RsiConfigBuilder, manifest: &mut RunManifest) {
     println!("Testing strategy context and custom data flow\n");
 
-    let config = RSIConfig {
-        rsi_period: 14,
-        oversold_threshold: 30.0,
-        overbought_threshold: 70.0,
-        position_size: 1.0,
-        use_dynamic_levels: true, // Enable dynamic levels for context
-        volatility_window: 20,
-        overbought_min: 65.0,
-        overbought_max: 85.0,
-        oversold_min: 15.0,
-        oversold_max: 35.0,
-        atr_period: 14,
-        atr_multiplier: 2.0,
-    };
+    let config = rsi_settings
+        .clone()
+        .position_size(1.0)
+        .use_dynamic_levels(true) // Enable dynamic levels for context
+        .build()
+        .expect("demo sizes are positive and loaded settings are already validated");
 
-    let rsi_strategy = RSIStrategy::new(config, 100000.0);
-    let mut rsi_wrapper = TickStrategyWrapper::new(rsi_strategy, 5);
+    let mut rsi_wrapper = build_wrapper("strategy_context", config, manifest);
 
     // Add context observer
     let context_observer = ContextDemo::new();
     rsi_wrapper.strategy_mut().add_observer(Box::new(context_observer));
 
+    // Record every trade so the context shown below can be exported afterwards
+    let journal = TradeJournal::new();
+    let journal_entries = journal.entries_handle();
+    rsi_wrapper.strategy_mut().add_observer(Box::new(journal));
+
     // Create different custom data for different users/sessions
     let users = ["alice", "bob", "charlie", "david", "eve"];
     let risk_levels = ["low", "medium", "high", "medium", "low"];
@@ -292,4 +374,145 @@ fn demo_strategy_context(ticks: &[MarketTick]) {
 
     let trades = rsi_wrapper.strategy().get_trades();
     println!("Total trades executed: {}", trades.len());
-}
\ No newline at end of file
+
+    // Set TRADE_JOURNAL=trades.csv (or .jsonl / .json) to persist the journal
+    if let Ok(journal_path) = std::env::var("TRADE_JOURNAL") {
+        let entries = journal_entries.lock().unwrap();
+        match reporting::export_to_path(&entries, Path::new(&journal_path)) {
+            Ok(()) => println!("Trade journal ({} entries) written to {}", entries.len(), journal_path),
+            Err(err) => println!("Warning: Could not write trade journal {}: {}", journal_path, err),
+        }
+    }
+}
+
+fn demo_replay(ticks: &[MarketTick], rsi_settings: &RsiConfigBuilder, manifest: &mut RunManifest) {
+    println!("Replaying ticks with speed control, stepping and pause/resume\n");
+
+    let config = rsi_settings
+        .clone()
+        .position_size(1.0)
+        .use_dynamic_levels(false)
+        .build()
+        .expect("demo sizes are positive and loaded settings are already validated");
+
+    // REPLAY_TICK_UNIT=ms (default) or =s says what one timestamp step is worth.
+    // tick_strategy_ticks.jsonl uses second timestamps, so it needs =s to play in real time.
+    let tick_unit = match std::env::var("REPLAY_TICK_UNIT").as_deref() {
+        Ok("s") => Duration::from_secs(1),
+        Ok("ms") | Err(_) => Duration::from_millis(1),
+        Ok(value) => {
+            println!("Warning: Invalid REPLAY_TICK_UNIT '{}', using milliseconds", value);
+            Duration::from_millis(1)
+        }
+    };
+
+    // REPLAY_SPEED=1 plays in real time on the wall clock, =100 at 100x, =max without waiting.
+    // Unset, the replay runs at 100x on a simulated clock so the demo finishes instantly.
+    match std::env::var("REPLAY_SPEED") {
+        Ok(value) if value == "max" => {
+            run_replay(ticks, config, manifest, tick_unit, ReplaySpeed::Unthrottled, SystemClock::new())
+        }
+        Ok(value) => match value.parse::<f64>() {
+            Ok(multiplier) if multiplier.is_finite() && multiplier >= MIN_MULTIPLIER => {
+                run_replay(ticks, config, manifest, tick_unit, ReplaySpeed::Multiplier(multiplier), SystemClock::new())
+            }
+            _ => {
+                println!("Warning: Invalid REPLAY_SPEED '{}' (expected 'max' or a number >= {}), using simulated 100x",
+                         value, MIN_MULTIPLIER);
+                run_replay(ticks, config, manifest, tick_unit, ReplaySpeed::Multiplier(100.0), SimulatedClock::default())
+            }
+        },
+        Err(_) => run_replay(ticks, config, manifest, tick_unit, ReplaySpeed::Multiplier(100.0), SimulatedClock::default()),
+    }
+}
+
+fn run_replay<C: Clock>(
+    ticks: &[MarketTick],
+    config: RSIConfig,
+    manifest: &mut RunManifest,
+    tick_unit: Duration,
+    speed: ReplaySpeed,
+    clock: C,
+) {
+    let mut rsi_wrapper = build_wrapper("replay", config, manifest);
+
+    let mut engine = ReplayEngine::new(ticks, tick_unit, clock).with_speed(speed);
+
+    // Step-by-step: inspect the first few ticks one at a time
+    for step in 1..=3 {
+        let stepped = engine.step(|tick| {
+            println!("Step {}: t={} price=${:.2}", step, tick.timestamp, tick.price);
+            rsi_wrapper.process_tick(tick, None);
+        });
+        if !stepped {
+            break;
+        }
+    }
+
+    // Pause halfway through (or right away if stepping already passed the midpoint),
+    // then resume to the end
+    let midpoint = ticks.len() / 2;
+    let mut delivered = engine.position();
+    let mut result = engine.run(|tick| {
+        rsi_wrapper.process_tick(tick, None);
+        delivered += 1;
+        if delivered >= midpoint {
+            ReplayControl::Pause
+        } else {
+            ReplayControl::Continue
+        }
+    });
+    if result.is_ok() && !engine.is_finished() {
+        println!("Paused at tick {}/{} (clock: {:.3}s)", engine.position(), ticks.len(),
+                 engine.clock().elapsed().as_secs_f64());
+        println!("Resuming...");
+        result = engine.run(|tick| {
+            rsi_wrapper.process_tick(tick, None);
+            ReplayControl::Continue
+        });
+    }
+    if let Err(err) = result {
+        println!("Warning: Replay stopped at tick {}/{}: {}", engine.position(), ticks.len(), err);
+    }
+
+    if let Some(last_tick) = ticks.last() {
+        rsi_wrapper.force_close_candle(last_tick.timestamp + 1000);
+    }
+
+    println!("\nReplay finished: {} ticks in {:.3}s on the clock",
+             engine.position(), engine.clock().elapsed().as_secs_f64());
+    println!("Total trades executed: {}", rsi_wrapper.strategy().get_trades().len());
+}
+
+fn demo_risk_manager(ticks: &[MarketTick], rsi_settings: &RsiConfigBuilder, manifest: &mut RunManifest) {
+    println!("Enforcing position size, notional and trade-count limits\n");
+
+    let config = rsi_settings
+        .clone()
+        .position_size(3.0) // Large size to trigger resizing
+        .use_dynamic_levels(false)
+        .build()
+        .expect("demo sizes are positive and loaded settings are already validated");
+
+    let mut rsi_wrapper = build_wrapper("risk_manager", config, manifest);
+
+    let risk_manager = RiskManager::new(RiskLimits {
+        max_position_size: Some(2.0),
+        max_notional: Some(5000.0),
+        max_trades: Some(5), // Kill switch after 5 trades
+    });
+    let risk_stats = risk_manager.stats_handle();
+    let kill_switch = risk_manager.kill_switch();
+    rsi_wrapper.strategy_mut().add_observer(Box::new(risk_manager));
+
+    for tick in ticks {
+        rsi_wrapper.process_tick(tick, None);
+    }
+
+    if let Some(last_tick) = ticks.last() {
+        rsi_wrapper.force_close_candle(last_tick.timestamp + 1000);
+    }
+
+    risk_stats.lock().unwrap().print("Risk Manager Results");
+    println!("  Kill switch engaged: {}", kill_switch.is_engaged());
+}
@@ -0,0 +1,171 @@
+use crate::config::RsiConfigRecord;
+use crate::data::loaders::{ColumnMapping, TimestampUnit};
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use trading_strategies::strategies::config::RSIConfig;
+
+// 64-bit FNV-1a; unlike DefaultHasher its output is stable across Rust versions and platforms
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+// Loader used for a data file, with the CSV column mapping it resolved to
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FileFormat {
+    Jsonl,
+    Csv { columns: ColumnMapping },
+}
+
+// Where the ticks for a run came from
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DataSource {
+    File {
+        // Written to the manifest file but not hashed: the content hash already identifies
+        // the data, and the same file can be spelled many ways on the command line
+        #[serde(skip)]
+        path: String,
+        format: FileFormat,
+        timestamp_unit: TimestampUnit,
+        fnv1a: Option<String>,
+    },
+    Synthetic { model: String, seed: u64 },
+    Sample,
+}
+
+impl DataSource {
+    // Hashes the file contents so a changed data file is caught on replay
+    pub fn file(path: &str, format: FileFormat, timestamp_unit: TimestampUnit) -> Self {
+        DataSource::File {
+            path: path.to_string(),
+            format,
+            timestamp_unit,
+            fnv1a: fs::read(path).ok().map(|bytes| format!("{:016x}", fnv1a(&bytes))),
+        }
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            DataSource::File { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyRecord {
+    pub name: String,
+    pub initial_capital: f64,
+    pub candle_interval: u64,
+    pub config: RsiConfigRecord,
+}
+
+// Everything needed to reproduce a run; the run ID is derived from these fields only,
+// so it does not depend on how the inputs were spelled on the command line
+#[derive(Debug, Clone, Serialize)]
+pub struct RunManifest {
+    pub crate_name: String,
+    pub crate_version: String,
+    pub data_source: DataSource,
+    pub tick_count: usize,
+    pub strategies: Vec<StrategyRecord>,
+}
+
+impl RunManifest {
+    pub fn new(data_source: DataSource, tick_count: usize) -> Self {
+        Self {
+            crate_name: env!("CARGO_PKG_NAME").to_string(),
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            data_source,
+            tick_count,
+            strategies: Vec::new(),
+        }
+    }
+
+    pub fn add_rsi_strategy(&mut self, name: &str, config: &RSIConfig, initial_capital: f64, candle_interval: u64) {
+        self.strategies.push(StrategyRecord {
+            name: name.to_string(),
+            initial_capital,
+            candle_interval,
+            config: RsiConfigRecord::from(config),
+        });
+    }
+
+    // Identical inputs always give the same ID
+    pub fn run_id(&self) -> String {
+        let canonical = serde_json::to_vec(self).expect("manifest fields are always serializable");
+        format!("{:016x}", fnv1a(&canonical))
+    }
+
+    pub fn write_to(&self, path: &Path) -> io::Result<()> {
+        #[derive(Serialize)]
+        struct Stamped<'a> {
+            run_id: String,
+            created_at: String,
+            // Raw command line and data path, for reference only; the resolved inputs are in the manifest
+            arguments: Vec<String>,
+            data_path: Option<&'a str>,
+            #[serde(flatten)]
+            manifest: &'a RunManifest,
+        }
+
+        let stamped = Stamped {
+            run_id: self.run_id(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            arguments: std::env::args().skip(1).collect(),
+            data_path: self.data_source.path(),
+            manifest: self,
+        };
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, &stamped)?;
+        writeln!(writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csv_manifest(columns: ColumnMapping) -> RunManifest {
+        RunManifest::new(DataSource::file("missing.csv", FileFormat::Csv { columns }, TimestampUnit::default()), 10)
+    }
+
+    #[test]
+    fn run_id_is_stable_for_the_same_resolved_inputs() {
+        assert_eq!(
+            csv_manifest(ColumnMapping::default()).run_id(),
+            csv_manifest(ColumnMapping::default()).run_id()
+        );
+    }
+
+    #[test]
+    fn run_id_ignores_how_the_data_path_is_spelled() {
+        let dir = std::env::temp_dir();
+        let name = format!("manifest_{}.jsonl", std::process::id());
+        fs::write(dir.join(&name), "{}\n").unwrap();
+        let spellings = [dir.join(&name), dir.join(".").join(&name)];
+        let ids: Vec<String> = spellings
+            .iter()
+            .map(|path| {
+                let source = DataSource::file(path.to_str().unwrap(), FileFormat::Jsonl, TimestampUnit::default());
+                RunManifest::new(source, 1).run_id()
+            })
+            .collect();
+        fs::remove_file(dir.join(&name)).unwrap();
+
+        assert_eq!(ids[0], ids[1]);
+        assert_ne!(ids[0], RunManifest::new(DataSource::Sample, 1).run_id());
+    }
+
+    #[test]
+    fn run_id_changes_with_the_column_mapping() {
+        let remapped = ColumnMapping { price: "close".to_string(), ..ColumnMapping::default() };
+        assert_ne!(csv_manifest(ColumnMapping::default()).run_id(), csv_manifest(remapped).run_id());
+    }
+}
@@ -0,0 +1,233 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use trading_strategies::core::{ProposedTrade, TradeDecision, TradeEvent, TradeObserver};
+use trading_strategies::core::types::TradeContext;
+
+// Limits checked before every trade; `None` disables a rule
+#[derive(Debug, Clone)]
+pub struct RiskLimits {
+    pub max_position_size: Option<f64>,
+    // Per-trade notional, price * quantity
+    pub max_notional: Option<f64>,
+    // The kill switch engages once this many trades have been let through
+    pub max_trades: Option<usize>,
+}
+
+// Counters for pre-trade decisions. Observers are moved into the strategy when they are
+// added, so they share these through an Arc<Mutex<_>> and the demo keeps a handle to read
+// the results after the run.
+#[derive(Debug, Default)]
+pub struct DecisionStats {
+    pub approved_count: usize,
+    pub modified_count: usize,
+    pub rejected_count: usize,
+}
+
+impl DecisionStats {
+    pub fn print(&self, title: &str) {
+        println!("{}:", title);
+        println!("  Approved: {}", self.approved_count);
+        println!("  Modified: {}", self.modified_count);
+        println!("  Rejected: {}", self.rejected_count);
+    }
+}
+
+// Shared halt flag; once engaged, the risk manager rejects every further trade
+#[derive(Debug, Clone, Default)]
+pub struct KillSwitch(Arc<AtomicBool>);
+
+impl KillSwitch {
+    pub fn engage(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RiskVerdict {
+    Approve,
+    Resize(f64),
+    Reject(&'static str),
+}
+
+// Ready-made pre-trade risk checks. Rules that need PnL or trade timestamps
+// (daily loss, trades per day) are not possible from a TradeObserver.
+pub struct RiskManager {
+    limits: RiskLimits,
+    stats: Arc<Mutex<DecisionStats>>,
+    kill_switch: KillSwitch,
+}
+
+impl RiskManager {
+    pub fn new(limits: RiskLimits) -> Self {
+        Self {
+            limits,
+            stats: Arc::new(Mutex::new(DecisionStats::default())),
+            kill_switch: KillSwitch::default(),
+        }
+    }
+
+    pub fn stats_handle(&self) -> Arc<Mutex<DecisionStats>> {
+        Arc::clone(&self.stats)
+    }
+
+    // Engaging the returned switch halts trading from outside the strategy
+    pub fn kill_switch(&self) -> KillSwitch {
+        self.kill_switch.clone()
+    }
+
+    fn evaluate(
+        limits: &RiskLimits,
+        stats: &mut DecisionStats,
+        kill_switch: &KillSwitch,
+        price: f64,
+        quantity: f64,
+    ) -> RiskVerdict {
+        if kill_switch.is_engaged() {
+            stats.rejected_count += 1;
+            return RiskVerdict::Reject("kill switch engaged");
+        }
+
+        let mut allowed = quantity;
+        if let Some(max_size) = limits.max_position_size {
+            allowed = allowed.min(max_size);
+        }
+        if let Some(max_notional) = limits.max_notional {
+            if price > 0.0 && price * allowed > max_notional {
+                allowed = max_notional / price;
+            }
+        }
+
+        // Limits of zero leave nothing to trade, so there is nothing to resize to
+        if allowed <= 0.0 {
+            stats.rejected_count += 1;
+            return RiskVerdict::Reject("no size allowed by limits");
+        }
+
+        let verdict = if allowed < quantity {
+            stats.modified_count += 1;
+            RiskVerdict::Resize(allowed)
+        } else {
+            stats.approved_count += 1;
+            RiskVerdict::Approve
+        };
+
+        if let Some(max_trades) = limits.max_trades {
+            if stats.approved_count + stats.modified_count >= max_trades {
+                kill_switch.engage();
+            }
+        }
+        verdict
+    }
+}
+
+impl TradeObserver for RiskManager {
+    fn pre_trade(&mut self, proposed_trade: &ProposedTrade, _context: TradeContext) -> TradeDecision {
+        let mut stats = self.stats.lock().unwrap();
+        let verdict = Self::evaluate(
+            &self.limits,
+            &mut stats,
+            &self.kill_switch,
+            proposed_trade.price,
+            proposed_trade.quantity,
+        );
+        match verdict {
+            RiskVerdict::Approve => TradeDecision::Approve,
+            RiskVerdict::Resize(quantity) => {
+                let mut modified_trade = proposed_trade.clone();
+                modified_trade.quantity = quantity;
+                TradeDecision::Modify(modified_trade)
+            }
+            RiskVerdict::Reject(reason) => TradeDecision::Reject(reason.to_string()),
+        }
+    }
+
+    fn post_trade(&mut self, _event: TradeEvent, _context: TradeContext) {
+        // Limits are enforced before execution only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> RiskLimits {
+        RiskLimits {
+            max_position_size: Some(2.0),
+            max_notional: Some(10_000.0),
+            max_trades: None,
+        }
+    }
+
+    fn evaluate(
+        limits: &RiskLimits,
+        stats: &mut DecisionStats,
+        switch: &KillSwitch,
+        price: f64,
+        quantity: f64,
+    ) -> RiskVerdict {
+        RiskManager::evaluate(limits, stats, switch, price, quantity)
+    }
+
+    #[test]
+    fn trades_within_limits_are_approved() {
+        let mut stats = DecisionStats::default();
+        assert_eq!(evaluate(&limits(), &mut stats, &KillSwitch::default(), 1000.0, 1.5), RiskVerdict::Approve);
+        assert_eq!(stats.approved_count, 1);
+    }
+
+    #[test]
+    fn oversized_trades_are_resized_to_the_tightest_limit() {
+        let mut stats = DecisionStats::default();
+        let switch = KillSwitch::default();
+        // Size limit binds: 2.0 * 1000 is within notional
+        assert_eq!(evaluate(&limits(), &mut stats, &switch, 1000.0, 3.0), RiskVerdict::Resize(2.0));
+        // Notional limit binds: 2.0 * 10000 exceeds 10000
+        assert_eq!(evaluate(&limits(), &mut stats, &switch, 10_000.0, 3.0), RiskVerdict::Resize(1.0));
+        assert_eq!(stats.modified_count, 2);
+    }
+
+    #[test]
+    fn zero_allowed_size_is_rejected_not_resized() {
+        let mut stats = DecisionStats::default();
+        let switch = KillSwitch::default();
+        let no_size = RiskLimits { max_position_size: Some(0.0), ..limits() };
+        let no_notional = RiskLimits { max_notional: Some(0.0), ..limits() };
+
+        assert!(matches!(evaluate(&no_size, &mut stats, &switch, 1000.0, 1.0), RiskVerdict::Reject(_)));
+        assert!(matches!(evaluate(&no_notional, &mut stats, &switch, 1000.0, 1.0), RiskVerdict::Reject(_)));
+        assert_eq!((stats.modified_count, stats.rejected_count), (0, 2));
+    }
+
+    #[test]
+    fn kill_switch_rejects_everything_after_max_trades() {
+        let limits = RiskLimits { max_trades: Some(2), ..limits() };
+        let mut stats = DecisionStats::default();
+        let switch = KillSwitch::default();
+        evaluate(&limits, &mut stats, &switch, 1000.0, 1.0);
+        evaluate(&limits, &mut stats, &switch, 1000.0, 3.0);
+        assert!(switch.is_engaged());
+        assert_eq!(
+            evaluate(&limits, &mut stats, &switch, 1000.0, 1.0),
+            RiskVerdict::Reject("kill switch engaged")
+        );
+        assert_eq!((stats.approved_count, stats.modified_count, stats.rejected_count), (1, 1, 1));
+    }
+
+    #[test]
+    fn kill_switch_handle_halts_the_manager() {
+        let manager = RiskManager::new(limits());
+        let handle = manager.kill_switch();
+        let mut stats = DecisionStats::default();
+        assert_eq!(evaluate(&manager.limits, &mut stats, &manager.kill_switch, 1000.0, 1.0), RiskVerdict::Approve);
+
+        handle.engage();
+        assert!(matches!(
+            evaluate(&manager.limits, &mut stats, &manager.kill_switch, 1000.0, 1.0),
+            RiskVerdict::Reject("kill switch engaged")
+        ));
+    }
+}
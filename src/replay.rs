@@ -0,0 +1,215 @@
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant};
+use trading_strategies::core::tick::TickData;
+
+// Time source used to pace a replay
+pub trait Clock {
+    fn elapsed(&self) -> Duration;
+    fn sleep(&mut self, duration: Duration);
+}
+
+// Wall clock: sleeping really blocks the thread
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self { start: Instant::now() }
+    }
+}
+
+impl Clock for SystemClock {
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+// Simulated clock: sleeping only advances the counter, so runs are instant and repeatable
+#[derive(Debug, Default)]
+pub struct SimulatedClock {
+    elapsed: Duration,
+}
+
+impl Clock for SimulatedClock {
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.elapsed += duration;
+    }
+}
+
+// Slowest supported playback (1000x slower than real time); anything below makes
+// ordinary tick gaps wait for longer than is useful
+pub const MIN_MULTIPLIER: f64 = 0.001;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReplaySpeed {
+    // 1.0 is real time, 100.0 plays 100x faster
+    Multiplier(f64),
+    Unthrottled,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    // The wait for a tick gap does not fit in a Duration (too long, or NaN)
+    WaitOutOfRange { gap: i64, multiplier: f64 },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::WaitOutOfRange { gap, multiplier } => write!(
+                f,
+                "cannot wait for a tick gap of {} at speed {}x",
+                gap, multiplier
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayControl {
+    Continue,
+    Pause,
+}
+
+// Plays historical ticks back in order, waiting between ticks according to the speed
+pub struct ReplayEngine<'a, T, C> {
+    ticks: &'a [T],
+    position: usize,
+    speed: ReplaySpeed,
+    tick_unit: Duration,
+    clock: C,
+}
+
+impl<'a, T: TickData, C: Clock> ReplayEngine<'a, T, C> {
+    // `tick_unit` is the duration of one timestamp step (e.g. 1ms for millisecond epochs)
+    pub fn new(ticks: &'a [T], tick_unit: Duration, clock: C) -> Self {
+        Self {
+            ticks,
+            position: 0,
+            speed: ReplaySpeed::Unthrottled,
+            tick_unit,
+            clock,
+        }
+    }
+
+    pub fn with_speed(mut self, speed: ReplaySpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.ticks.len()
+    }
+
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    // Deliver the next tick immediately, regardless of speed; false once the data is exhausted
+    pub fn step(&mut self, on_tick: impl FnOnce(&T)) -> bool {
+        match self.ticks.get(self.position) {
+            Some(tick) => {
+                self.position += 1;
+                on_tick(tick);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Play until the data runs out or `on_tick` asks to pause; call again to resume.
+    // Stops before delivering a tick whose wait cannot be represented.
+    pub fn run(&mut self, mut on_tick: impl FnMut(&T) -> ReplayControl) -> Result<(), ReplayError> {
+        while let Some(tick) = self.ticks.get(self.position) {
+            self.wait_for(tick)?;
+            self.position += 1;
+            if on_tick(tick) == ReplayControl::Pause {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn wait_for(&mut self, tick: &T) -> Result<(), ReplayError> {
+        let ReplaySpeed::Multiplier(multiplier) = self.speed else {
+            return Ok(());
+        };
+        let Some(previous) = self.position.checked_sub(1).map(|i| &self.ticks[i]) else {
+            return Ok(());
+        };
+
+        let gap = tick.timestamp().saturating_sub(previous.timestamp());
+        if gap <= 0 {
+            return Ok(());
+        }
+        let seconds = self.tick_unit.as_secs_f64() * gap as f64 / multiplier;
+        let wait = Duration::try_from_secs_f64(seconds)
+            .map_err(|_| ReplayError::WaitOutOfRange { gap, multiplier })?;
+        self.clock.sleep(wait);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Tick(i64);
+
+    impl TickData for Tick {
+        fn timestamp(&self) -> i64 { self.0 }
+        fn price(&self) -> f64 { 1.0 }
+        fn volume(&self) -> f64 { 0.0 }
+        fn symbol(&self) -> &str { "TEST" }
+    }
+
+    #[test]
+    fn multiplier_scales_waits_on_the_simulated_clock() {
+        let ticks = [Tick(0), Tick(1000), Tick(3000)];
+        let mut engine = ReplayEngine::new(&ticks, Duration::from_millis(1), SimulatedClock::default())
+            .with_speed(ReplaySpeed::Multiplier(10.0));
+        engine.run(|_| ReplayControl::Continue).unwrap();
+
+        assert!(engine.is_finished());
+        assert_eq!(engine.clock().elapsed(), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn pause_stops_and_run_resumes() {
+        let ticks = [Tick(0), Tick(1), Tick(2), Tick(3)];
+        let mut engine = ReplayEngine::new(&ticks, Duration::from_millis(1), SimulatedClock::default());
+        assert!(engine.step(|_| {}));
+        engine.run(|_| ReplayControl::Pause).unwrap();
+        assert_eq!(engine.position(), 2);
+        engine.run(|_| ReplayControl::Continue).unwrap();
+        assert!(engine.is_finished());
+        assert!(!engine.step(|_| {}));
+    }
+
+    #[test]
+    fn unrepresentable_wait_is_an_error_not_a_panic() {
+        let ticks = [Tick(0), Tick(i64::MAX)];
+        let mut engine = ReplayEngine::new(&ticks, Duration::from_secs(1), SimulatedClock::default())
+            .with_speed(ReplaySpeed::Multiplier(MIN_MULTIPLIER));
+        let result = engine.run(|_| ReplayControl::Continue);
+
+        assert!(matches!(result, Err(ReplayError::WaitOutOfRange { .. })));
+        assert_eq!(engine.position(), 1);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::data::TradeMetadata;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use trading_strategies::core::{ProposedTrade, TradeDecision, TradeEvent, TradeObserver};
+use trading_strategies::core::types::TradeContext;
+use trading_strategies::strategies::rsi::RsiTradeContext;
+
+// RSI levels at the time of the trade
+#[derive(Debug, Clone, Serialize)]
+pub struct RsiSnapshot {
+    pub rsi_value: f64,
+    pub overbought_level: f64,
+    pub oversold_level: f64,
+}
+
+// One completed trade with the strategy context and custom data it carried
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalEntry {
+    pub trade_number: usize,
+    pub side: String,
+    pub price: f64,
+    pub rsi: Option<RsiSnapshot>,
+    pub metadata: Option<TradeMetadata>,
+}
+
+impl JournalEntry {
+    pub fn from_event(trade_number: usize, event: &TradeEvent, context: &TradeContext) -> Self {
+        let (side, trade) = match event {
+            TradeEvent::Buy(trade) => ("Long", trade),
+            TradeEvent::Sell(trade) => ("Short", trade),
+        };
+        let rsi = context.strategy_context
+            .and_then(|ctx| ctx.downcast_ref::<RsiTradeContext>())
+            .map(|ctx| RsiSnapshot {
+                rsi_value: ctx.rsi_value,
+                overbought_level: ctx.dynamic_overbought,
+                oversold_level: ctx.dynamic_oversold,
+            });
+        let metadata = context.custom_data
+            .and_then(|data| data.downcast_ref::<TradeMetadata>())
+            .cloned();
+
+        Self {
+            trade_number,
+            side: side.to_string(),
+            price: trade.exit_price,
+            rsi,
+            metadata,
+        }
+    }
+}
+
+// Observer that records every trade event for export after the run
+pub struct TradeJournal {
+    entries: Arc<Mutex<Vec<JournalEntry>>>,
+}
+
+impl TradeJournal {
+    pub fn new() -> Self {
+        Self { entries: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    pub fn entries_handle(&self) -> Arc<Mutex<Vec<JournalEntry>>> {
+        Arc::clone(&self.entries)
+    }
+}
+
+impl TradeObserver for TradeJournal {
+    fn pre_trade(&mut self, _proposed_trade: &ProposedTrade, _context: TradeContext) -> TradeDecision {
+        TradeDecision::Approve
+    }
+
+    fn post_trade(&mut self, event: TradeEvent, context: TradeContext) {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = JournalEntry::from_event(entries.len() + 1, &event, &context);
+        entries.push(entry);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JournalFormat {
+    Csv,
+    Jsonl,
+    PrettyJson,
+}
+
+impl JournalFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "csv" => Some(JournalFormat::Csv),
+            "jsonl" => Some(JournalFormat::Jsonl),
+            "json" => Some(JournalFormat::PrettyJson),
+            _ => None,
+        }
+    }
+}
+
+pub fn export<W: Write>(entries: &[JournalEntry], format: JournalFormat, mut writer: W) -> io::Result<()> {
+    match format {
+        JournalFormat::Csv => write_csv(entries, &mut writer)?,
+        JournalFormat::Jsonl => {
+            for entry in entries {
+                serde_json::to_writer(&mut writer, entry)?;
+                writeln!(writer)?;
+            }
+        }
+        JournalFormat::PrettyJson => {
+            serde_json::to_writer_pretty(&mut writer, entries)?;
+            writeln!(writer)?;
+        }
+    }
+    writer.flush()
+}
+
+// Picks the format from the file extension (.csv, .jsonl or .json)
+pub fn export_to_path(entries: &[JournalEntry], path: &Path) -> io::Result<()> {
+    let format = JournalFormat::from_path(path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported journal format: {}", path.display()),
+        )
+    })?;
+    export(entries, format, BufWriter::new(File::create(path)?))
+}
+
+fn write_csv<W: Write>(entries: &[JournalEntry], writer: &mut W) -> io::Result<()> {
+    writeln!(
+        writer,
+        "trade_number,side,price,rsi_value,overbought_level,oversold_level,user_id,session_id,risk_level"
+    )?;
+    for entry in entries {
+        // Missing context or custom data leaves its columns empty
+        let (rsi_value, overbought_level, oversold_level) = match &entry.rsi {
+            Some(rsi) => (
+                rsi.rsi_value.to_string(),
+                rsi.overbought_level.to_string(),
+                rsi.oversold_level.to_string(),
+            ),
+            None => Default::default(),
+        };
+        let (user_id, session_id, risk_level) = match &entry.metadata {
+            Some(metadata) => (
+                metadata.user_id.as_str(),
+                metadata.session_id.as_str(),
+                metadata.risk_level.as_str(),
+            ),
+            None => ("", "", ""),
+        };
+
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{}",
+            entry.trade_number,
+            csv_field(&entry.side),
+            entry.price,
+            rsi_value,
+            overbought_level,
+            oversold_level,
+            csv_field(user_id),
+            csv_field(session_id),
+            csv_field(risk_level),
+        )?;
+    }
+    Ok(())
+}
+
+// Quote fields containing separators, quotes or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}